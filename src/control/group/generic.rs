@@ -0,0 +1,142 @@
+use super::super::{BitMask, Tag};
+use core::mem;
+
+#[cfg(target_pointer_width = "64")]
+type GroupWord = u64;
+#[cfg(target_pointer_width = "32")]
+type GroupWord = u32;
+
+#[cfg(target_pointer_width = "64")]
+pub(crate) type NonZeroBitMaskWord = core::num::NonZeroU64;
+#[cfg(target_pointer_width = "32")]
+pub(crate) type NonZeroBitMaskWord = core::num::NonZeroU32;
+
+pub(crate) type BitMaskWord = GroupWord;
+pub(crate) const BITMASK_STRIDE: usize = 8;
+pub(crate) const BITMASK_MASK: BitMaskWord = repeat(0x80);
+pub(crate) const BITMASK_ITER_MASK: BitMaskWord = BITMASK_MASK;
+
+/// Repeats the given byte across every byte lane of a `GroupWord`.
+#[inline]
+const fn repeat(byte: u8) -> GroupWord {
+    GroupWord::from_ne_bytes([byte; Group::WIDTH])
+}
+
+/// Abstraction over a group of control tags which can be scanned in
+/// parallel.
+///
+/// This implementation uses a word-at-a-time (SWAR, "SIMD within a
+/// register") technique to replicate the effect of a SIMD vector register
+/// using only normal integer operations. This is a fallback for hardware
+/// which doesn't support the SSE2 or NEON instructions used by the other
+/// implementations.
+///
+/// The technique used here is described in:
+/// <https://graphics.stanford.edu/~seander/bithacks.html##ZeroInWord>
+#[derive(Copy, Clone)]
+pub(crate) struct Group(GroupWord);
+
+// We perform all operations in the native endianness, and convert to
+// little-endian just before creating a `BitMask`. The can potentially
+// enable the compiler to eliminate unnecessary byte swaps if we are
+// only checking whether a `BitMask` is empty.
+#[allow(clippy::use_self)]
+impl Group {
+    /// Number of bytes in the group.
+    pub(crate) const WIDTH: usize = mem::size_of::<Self>();
+
+    /// Returns a full group of empty tags, suitable for use as the initial
+    /// value for an empty hash table.
+    ///
+    /// This is guaranteed to be aligned to the group size.
+    #[inline]
+    pub(crate) const fn static_empty() -> &'static [Tag; Group::WIDTH] {
+        #[repr(C)]
+        struct AlignedTags {
+            _align: [Group; 0],
+            tags: [Tag; Group::WIDTH],
+        }
+        const ALIGNED_TAGS: AlignedTags = AlignedTags {
+            _align: [],
+            tags: [Tag::EMPTY; Group::WIDTH],
+        };
+        &ALIGNED_TAGS.tags
+    }
+
+    /// Loads a group of tags starting at the given address.
+    #[inline]
+    #[allow(clippy::cast_ptr_alignment)] // unaligned load
+    pub(crate) unsafe fn load(ptr: *const Tag) -> Self {
+        Group(ptr.cast::<GroupWord>().read_unaligned())
+    }
+
+    /// Loads a group of tags starting at the given address, which must be
+    /// aligned to `mem::align_of::<Group>()`.
+    #[inline]
+    #[allow(clippy::cast_ptr_alignment)]
+    pub(crate) unsafe fn load_aligned(ptr: *const Tag) -> Self {
+        debug_assert_eq!(ptr.align_offset(mem::align_of::<Self>()), 0);
+        Group(ptr.cast::<GroupWord>().read())
+    }
+
+    /// Stores the group of tags to the given address, which must be
+    /// aligned to `mem::align_of::<Group>()`.
+    #[inline]
+    #[allow(clippy::cast_ptr_alignment)]
+    pub(crate) unsafe fn store_aligned(self, ptr: *mut Tag) {
+        debug_assert_eq!(ptr.align_offset(mem::align_of::<Self>()), 0);
+        ptr.cast::<GroupWord>().write(self.0);
+    }
+
+    /// Returns a `BitMask` indicating all tags in the group which *may*
+    /// have the given value.
+    #[inline]
+    pub(crate) fn match_tag(self, tag: Tag) -> BitMask {
+        // This algorithm is derived from
+        // https://graphics.stanford.edu/~seander/bithacks.html##ValueInWord
+        let cmp = self.0 ^ repeat(tag.0);
+        BitMask((cmp.wrapping_sub(repeat(0x01)) & !cmp & repeat(0x80)).to_le())
+    }
+
+    /// Returns a `BitMask` indicating all tags in the group which are
+    /// `EMPTY`.
+    #[inline]
+    pub(crate) fn match_empty(self) -> BitMask {
+        // If the high bit is set, then the tag must be either:
+        // 1111_1111 (EMPTY) or 1000_0000 (DELETED).
+        // So we can just check if the top two bits are 1 by ANDing them.
+        BitMask((self.0 & (self.0 << 1) & repeat(0x80)).to_le())
+    }
+
+    /// Returns a `BitMask` indicating all tags in the group which are
+    /// `EMPTY` or `DELETED`.
+    #[inline]
+    pub(crate) fn match_empty_or_deleted(self) -> BitMask {
+        // A tag is EMPTY or DELETED iff the high bit is set.
+        BitMask((self.0 & repeat(0x80)).to_le())
+    }
+
+    /// Returns a `BitMask` indicating all tags in the group which are full.
+    #[inline]
+    pub(crate) fn match_full(self) -> BitMask {
+        // A tag is FULL iff the high bit is clear.
+        BitMask((!self.0 & repeat(0x80)).to_le())
+    }
+
+    /// Performs the following transformation on all tags in the group:
+    /// - `EMPTY => EMPTY`
+    /// - `DELETED => EMPTY`
+    /// - `FULL => DELETED`
+    #[inline]
+    pub(crate) fn convert_special_to_empty_and_full_to_deleted(self) -> Self {
+        // Map high_bit = 1 (EMPTY or DELETED) to 1111_1111
+        // and high_bit = 0 (FULL) to 1000_0000
+        //
+        // Here's this logic expanded to concrete values:
+        //   let full = 1000_0000 (true) or 0000_0000 (false)
+        //   !1000_0000 + 1000_0000 >> 7 = 0111_1111 + 0000_0001 = 1000_0000
+        //   !0000_0000 + 0000_0000 >> 7 = 1111_1111 + 0000_0000 = 1111_1111
+        let full = !self.0 & repeat(0x80);
+        Group(!full + (full >> 7))
+    }
+}
@@ -10,7 +10,7 @@ pub(crate) const BITMASK_MASK: BitMaskWord = !0;
 pub(crate) const BITMASK_ITER_MASK: BitMaskWord = 0x8888_8888_8888_8888;
 
 #[inline]
-fn cmp_to_word(cmp: neon::uint8x16_t) -> BitMaskWord {
+pub(super) fn cmp_to_word(cmp: neon::uint8x16_t) -> BitMaskWord {
     unsafe {
         let cmp = neon::vreinterpretq_u16_u8(cmp);
         let res = neon::vshrn_n_u16(cmp, 4);
@@ -109,6 +109,39 @@ impl Group {
         }
     }
 
+    /// Returns `BitMask`s indicating which tags in the group match `a` and
+    /// `b` respectively, running both comparisons on the same loaded group.
+    ///
+    /// This is useful for lookup-then-insert paths, which otherwise need to
+    /// find both a specific tag (to check for an existing entry) and a slot
+    /// to insert into (for example with `b = Tag::EMPTY`), reloading the
+    /// group for the second query. Each mask matches a single tag value, so
+    /// `EMPTY` and `DELETED` slots are not matched together; see
+    /// `match_tag_and_empty_or_deleted` for that.
+    #[inline]
+    pub(crate) fn match_tag_pair(self, a: Tag, b: Tag) -> (BitMask, BitMask) {
+        unsafe {
+            let cmp_a = neon::vceqq_u8(self.0, neon::vdupq_n_u8(a.0));
+            let cmp_b = neon::vceqq_u8(self.0, neon::vdupq_n_u8(b.0));
+            (BitMask(cmp_to_word(cmp_a)), BitMask(cmp_to_word(cmp_b)))
+        }
+    }
+
+    /// Returns `BitMask`s indicating which tags in the group match `tag` and
+    /// which are `EMPTY` or `DELETED`, running both comparisons on the same
+    /// loaded group.
+    #[inline]
+    pub(crate) fn match_tag_and_empty_or_deleted(self, tag: Tag) -> (BitMask, BitMask) {
+        unsafe {
+            let cmp_tag = neon::vceqq_u8(self.0, neon::vdupq_n_u8(tag.0));
+            let cmp_empty = neon::vcltzq_s8(neon::vreinterpretq_s8_u8(self.0));
+            (
+                BitMask(cmp_to_word(cmp_tag)),
+                BitMask(cmp_to_word(cmp_empty)),
+            )
+        }
+    }
+
     /// Performs the following transformation on all tags in the group:
     /// - `EMPTY => EMPTY`
     /// - `DELETED => EMPTY`
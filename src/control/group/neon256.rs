@@ -0,0 +1,191 @@
+use super::super::Tag;
+use super::neon128::{self, cmp_to_word};
+use core::arch::aarch64 as neon;
+use core::mem;
+use core::num::NonZeroU64;
+
+/// A `BitMask` over a [`WideGroup`], covering twice as many lanes as the
+/// 64-bit `BitMask` of the regular 128-bit `Group`.
+///
+/// Internally this is just the two 64-bit nibble-packed masks produced by
+/// each half of the wide group, stored low-half-first so that iteration
+/// yields slots in ascending address order.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) struct WideBitMask(u64, u64);
+
+impl WideBitMask {
+    /// Returns a new `WideBitMask` with all bits inverted.
+    #[inline]
+    #[must_use]
+    pub(crate) fn invert(self) -> Self {
+        WideBitMask(
+            self.0 ^ neon128::BITMASK_MASK,
+            self.1 ^ neon128::BITMASK_MASK,
+        )
+    }
+
+    /// Returns `true` if all the bits in the mask are `0`.
+    #[inline]
+    pub(crate) fn is_empty(self) -> bool {
+        self.0 == 0 && self.1 == 0
+    }
+
+    /// Returns the lowest lane index which is set, if any.
+    #[inline]
+    pub(crate) fn lowest_set_bit(self) -> Option<usize> {
+        if let Some(bit) = NonZeroU64::new(self.0) {
+            Some(Self::nth_bit_to_lane(bit.trailing_zeros() as usize))
+        } else {
+            NonZeroU64::new(self.1)
+                .map(|bit| 16 + Self::nth_bit_to_lane(bit.trailing_zeros() as usize))
+        }
+    }
+
+    #[inline]
+    fn nth_bit_to_lane(bit: usize) -> usize {
+        bit / neon128::BITMASK_STRIDE
+    }
+
+    /// Iterates over the lane indices whose bit is set, in ascending order.
+    ///
+    /// `cmp_to_word` packs each lane's match result as a nibble that's
+    /// `0xF` when matched, so we mask down to one bit per nibble with
+    /// `BITMASK_ITER_MASK` first; otherwise each matched lane's nibble
+    /// would produce four `next()` calls instead of one.
+    #[inline]
+    pub(crate) fn into_iter(self) -> impl Iterator<Item = usize> {
+        let mut lo = self.0 & neon128::BITMASK_ITER_MASK;
+        let mut hi = self.1 & neon128::BITMASK_ITER_MASK;
+        core::iter::from_fn(move || {
+            if let Some(bit) = NonZeroU64::new(lo) {
+                lo &= lo - 1;
+                return Some(Self::nth_bit_to_lane(bit.trailing_zeros() as usize));
+            }
+            if let Some(bit) = NonZeroU64::new(hi) {
+                hi &= hi - 1;
+                return Some(16 + Self::nth_bit_to_lane(bit.trailing_zeros() as usize));
+            }
+            None
+        })
+    }
+}
+
+/// Abstraction over two groups of control tags which can be scanned in
+/// parallel using a pair of NEON registers.
+///
+/// This doubles the number of slots examined per probe step (32 instead of
+/// 16), which shortens probe sequences for large tables at the cost of a
+/// second vector load/compare per step.
+#[derive(Copy, Clone)]
+#[repr(align(32))]
+pub(crate) struct WideGroup(neon::uint8x16_t, neon::uint8x16_t);
+
+#[allow(clippy::use_self)]
+impl WideGroup {
+    /// Number of bytes covered by the group.
+    pub(crate) const WIDTH: usize = mem::size_of::<Self>();
+
+    /// Returns a full group of empty tags, suitable for use as the initial
+    /// value for an empty hash table.
+    ///
+    /// This is guaranteed to be aligned to the group size.
+    #[inline]
+    pub(crate) const fn static_empty() -> &'static [Tag; WideGroup::WIDTH] {
+        #[repr(C)]
+        struct AlignedTags {
+            _align: [WideGroup; 0],
+            tags: [Tag; WideGroup::WIDTH],
+        }
+        const ALIGNED_TAGS: AlignedTags = AlignedTags {
+            _align: [],
+            tags: [Tag::EMPTY; WideGroup::WIDTH],
+        };
+        &ALIGNED_TAGS.tags
+    }
+
+    /// Loads a group of tags starting at the given address.
+    #[inline]
+    #[allow(clippy::cast_ptr_alignment)] // unaligned load
+    pub(crate) unsafe fn load(ptr: *const Tag) -> Self {
+        let lo = neon::vld1q_u8(ptr.cast());
+        let hi = neon::vld1q_u8(ptr.add(16).cast());
+        WideGroup(lo, hi)
+    }
+
+    /// Loads a group of tags starting at the given address, which must be
+    /// aligned to `mem::align_of::<WideGroup>()`.
+    #[inline]
+    #[allow(clippy::cast_ptr_alignment)]
+    pub(crate) unsafe fn load_aligned(ptr: *const Tag) -> Self {
+        debug_assert_eq!(ptr.align_offset(mem::align_of::<Self>()), 0);
+        Self::load(ptr)
+    }
+
+    /// Stores the group of tags to the given address, which must be
+    /// aligned to `mem::align_of::<WideGroup>()`.
+    #[inline]
+    #[allow(clippy::cast_ptr_alignment)]
+    pub(crate) unsafe fn store_aligned(self, ptr: *mut Tag) {
+        debug_assert_eq!(ptr.align_offset(mem::align_of::<Self>()), 0);
+        neon::vst1q_u8(ptr.cast(), self.0);
+        neon::vst1q_u8(ptr.add(16).cast(), self.1);
+    }
+
+    /// Returns a `WideBitMask` indicating all tags in the group which *may*
+    /// have the given value.
+    #[inline]
+    pub(crate) fn match_tag(self, tag: Tag) -> WideBitMask {
+        unsafe {
+            let needle = neon::vdupq_n_u8(tag.0);
+            let lo = cmp_to_word(neon::vceqq_u8(self.0, needle));
+            let hi = cmp_to_word(neon::vceqq_u8(self.1, needle));
+            WideBitMask(lo, hi)
+        }
+    }
+
+    /// Returns a `WideBitMask` indicating all tags in the group which are
+    /// `EMPTY`.
+    #[inline]
+    pub(crate) fn match_empty(self) -> WideBitMask {
+        self.match_tag(Tag::EMPTY)
+    }
+
+    /// Returns a `WideBitMask` indicating all tags in the group which are
+    /// `EMPTY` or `DELETED`.
+    #[inline]
+    pub(crate) fn match_empty_or_deleted(self) -> WideBitMask {
+        unsafe {
+            let lo = cmp_to_word(neon::vcltzq_s8(neon::vreinterpretq_s8_u8(self.0)));
+            let hi = cmp_to_word(neon::vcltzq_s8(neon::vreinterpretq_s8_u8(self.1)));
+            WideBitMask(lo, hi)
+        }
+    }
+
+    /// Returns a `WideBitMask` indicating all tags in the group which are
+    /// full.
+    #[inline]
+    pub(crate) fn match_full(self) -> WideBitMask {
+        unsafe {
+            let lo = cmp_to_word(neon::vcgezq_s8(neon::vreinterpretq_s8_u8(self.0)));
+            let hi = cmp_to_word(neon::vcgezq_s8(neon::vreinterpretq_s8_u8(self.1)));
+            WideBitMask(lo, hi)
+        }
+    }
+
+    /// Performs the following transformation on all tags in the group:
+    /// - `EMPTY => EMPTY`
+    /// - `DELETED => EMPTY`
+    /// - `FULL => DELETED`
+    #[inline]
+    pub(crate) fn convert_special_to_empty_and_full_to_deleted(self) -> Self {
+        unsafe {
+            let eighty = neon::vdupq_n_u8(0x80);
+            let lo_special = neon::vcltzq_s8(neon::vreinterpretq_s8_u8(self.0));
+            let hi_special = neon::vcltzq_s8(neon::vreinterpretq_s8_u8(self.1));
+            WideGroup(
+                neon::vorrq_u8(lo_special, eighty),
+                neon::vorrq_u8(hi_special, eighty),
+            )
+        }
+    }
+}
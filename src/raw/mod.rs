@@ -0,0 +1,3 @@
+pub mod simd;
+
+pub use simd::{BitMask, BitMaskIter, Group};
@@ -0,0 +1,122 @@
+//! A small public façade over the crate's internal, architecture-specific
+//! control-byte scanning code.
+//!
+//! The crate's hash tables scan a group of control bytes at a time (16 with
+//! NEON, 8 or 4 with the portable SWAR fallback, depending on pointer
+//! width) using whichever of NEON, SSE2, or the SWAR fallback is available
+//! for the target. That scanning code used to be entirely
+//! `pub(crate)`, which meant anyone building a different open-addressing
+//! structure on the same trick had to vendor a copy of it. This module
+//! re-exports a stable, minimal slice of it: load a group of tags, find
+//! which ones match a byte, and iterate over the resulting positions.
+
+use crate::control::group;
+use crate::control::Tag;
+use core::mem;
+
+/// A group of control tags that can be scanned for a matching byte in a
+/// single operation.
+///
+/// The group width (the number of tags scanned at once) and the scanning
+/// strategy (NEON, SSE2, or a portable word-at-a-time fallback) are chosen
+/// automatically for the target. [`Group::WIDTH`] tells you how many tags a
+/// single `Group` covers.
+#[derive(Copy, Clone)]
+pub struct Group(group::Group);
+
+impl Group {
+    /// Number of tags scanned by a single `Group`.
+    pub const WIDTH: usize = mem::size_of::<group::Group>();
+
+    /// Loads a group of tags starting at the given address.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to at least `Group::WIDTH` readable bytes.
+    #[inline]
+    pub unsafe fn load(ptr: *const u8) -> Self {
+        Group(group::Group::load(ptr.cast::<Tag>()))
+    }
+
+    /// Returns a [`BitMask`] indicating all tags in the group which *may*
+    /// equal `byte`.
+    ///
+    /// With the NEON backend a set bit means the tag byte equals `byte`.
+    /// With the portable word-at-a-time fallback a set bit can also be a
+    /// false positive for a tag that does not equal `byte`. A set bit says
+    /// nothing about whether that slot is otherwise meaningful to the
+    /// caller.
+    #[inline]
+    #[must_use]
+    pub fn match_byte(self, byte: u8) -> BitMask {
+        BitMask(self.0.match_tag(Tag(byte)))
+    }
+
+    /// Returns a [`BitMask`] indicating all tags in the group equal to
+    /// [`Tag::EMPTY`]'s byte value (`0xFF`).
+    ///
+    /// The portable word-at-a-time fallback only tests the top two bits of
+    /// each tag, so it also reports any byte in `0xC0..=0xFE`.
+    #[inline]
+    #[must_use]
+    pub fn match_empty(self) -> BitMask {
+        BitMask(self.0.match_empty())
+    }
+
+    /// Returns a [`BitMask`] indicating all tags in the group whose high bit
+    /// is clear, i.e. those hashbrown would treat as occupied ("full")
+    /// slots.
+    #[inline]
+    #[must_use]
+    pub fn match_full(self) -> BitMask {
+        BitMask(self.0.match_full())
+    }
+}
+
+/// The result of scanning a [`Group`]: a packed bitmask with one bit (or
+/// group of bits, depending on the backend) per tag that matched.
+///
+/// Iterate over it to get the byte offsets, within the group, of the
+/// matching tags in ascending order.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct BitMask(crate::control::BitMask);
+
+/// An iterator over the offsets of matching tags in a [`BitMask`], in
+/// ascending order.
+///
+/// This wraps the crate-internal iterator so that it, rather than the
+/// internal type itself, is what appears in this module's public API.
+pub struct BitMaskIter(crate::control::BitMaskIter);
+
+impl Iterator for BitMaskIter {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        self.0.next()
+    }
+}
+
+impl IntoIterator for BitMask {
+    type Item = usize;
+    type IntoIter = BitMaskIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        BitMaskIter(self.0.into_iter())
+    }
+}
+
+impl BitMask {
+    /// Returns `true` if no tag in the group matched.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the offset of the first matching tag, if any.
+    #[inline]
+    pub fn lowest_set_bit(self) -> Option<usize> {
+        self.0.lowest_set_bit()
+    }
+}